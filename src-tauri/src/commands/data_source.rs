@@ -1,33 +1,77 @@
 // Data source management commands for Tauri
+use crate::data_source::ingest::ValidationReport;
 use crate::data_source::types::DataSource;
-use crate::error::AppError;
+use crate::data_source::{ingest, store};
+use crate::error::{retry_with_backoff, AppError, ErrorCategory, ErrorSeverity};
+use crate::{app_err, trace_err};
+use std::time::Duration;
+
+/// Reading a configured data source touches a file that may live on a
+/// flaky network mount, so transient failures (see the retryable
+/// `std::io::Error` kinds in `error.rs`) get a few quick retries before
+/// giving up.
+const READ_RETRY_ATTEMPTS: u32 = 3;
+const READ_RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
 
 #[tauri::command]
-pub async fn add_data_source(source: DataSource) -> Result<DataSource, String> {
-    // TODO: Implement add data source command
-    Err("Not implemented".to_string())
+pub async fn add_data_source(source: DataSource) -> Result<DataSource, AppError> {
+    Ok(store::insert(source))
 }
 
 #[tauri::command]
-pub async fn list_data_sources() -> Result<Vec<DataSource>, String> {
-    // TODO: Implement list data sources command
-    Err("Not implemented".to_string())
+pub async fn list_data_sources() -> Result<Vec<DataSource>, AppError> {
+    Ok(store::list())
 }
 
 #[tauri::command]
-pub async fn remove_data_source(id: String) -> Result<(), String> {
-    // TODO: Implement remove data source command
-    Err("Not implemented".to_string())
+pub async fn remove_data_source(id: String) -> Result<(), AppError> {
+    store::remove(&id);
+    Ok(())
 }
 
 #[tauri::command]
-pub async fn validate_data_source(source: DataSource) -> Result<bool, String> {
-    // TODO: Implement data source validation
-    Err("Not implemented".to_string())
+pub async fn validate_data_source(source: DataSource) -> Result<ValidationReport, AppError> {
+    let (report, file_size) = retry_with_backoff(
+        || async {
+            let report = trace_err!(ingest::validate_events(&source.configuration))?;
+            let file_size =
+                trace_err!(std::fs::metadata(ingest::file_path(&source.configuration)))?.len();
+            Ok((report, file_size))
+        },
+        READ_RETRY_ATTEMPTS,
+        READ_RETRY_BASE_DELAY,
+    )
+    .await?;
+
+    // Update the canonical stored record's metadata rather than trusting the
+    // caller's full payload, which may be stale (e.g. a frontend that hasn't
+    // picked up a status change made elsewhere since it last fetched this
+    // source).
+    let mut stored = store::get(&source.id).unwrap_or(source);
+    stored.metadata.row_count = Some(report.row_count);
+    stored.metadata.file_size = Some(file_size);
+    stored.metadata.last_validated = Some(chrono::Utc::now().to_rfc3339());
+    store::insert(stored);
+
+    // Return the full report, not just a pass/fail bool, so the frontend can
+    // show *why* a source is invalid (parse-error count, failed mappings).
+    Ok(report)
 }
 
 #[tauri::command]
-pub async fn preview_data_source(id: String, limit: usize) -> Result<serde_json::Value, String> {
-    // TODO: Implement data preview
-    Err("Not implemented".to_string())
-}
\ No newline at end of file
+pub async fn preview_data_source(id: String, limit: usize) -> Result<serde_json::Value, AppError> {
+    let source = store::get(&id).ok_or_else(|| {
+        app_err!(
+            format!("unknown data source '{}'", id),
+            ErrorCategory::Validation,
+            ErrorSeverity::Warning
+        )
+    })?;
+    let preview = retry_with_backoff(
+        || async { trace_err!(ingest::preview_events(&source.configuration, limit)) },
+        READ_RETRY_ATTEMPTS,
+        READ_RETRY_BASE_DELAY,
+    )
+    .await?;
+    Ok(trace_err!(serde_json::to_value(preview))?)
+}
@@ -0,0 +1,7 @@
+// Log query commands for Tauri
+use crate::logging::{self, LogRecord, RecordFilter};
+
+#[tauri::command]
+pub async fn get_logs(filter: RecordFilter) -> Result<Vec<LogRecord>, String> {
+    Ok(logging::query_logs(&filter))
+}
@@ -0,0 +1,2 @@
+pub mod data_source;
+pub mod logging;
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A single visualized data point produced by ingesting a data source.
+///
+/// Rows where every mapped column parses into the expected schema become
+/// [`Event::TypeSafe`]; anything else falls back to [`Event::Dynamic`],
+/// still carrying the raw row, so malformed data is visualized instead of
+/// silently dropped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Event {
+    TypeSafe(StrongEvent),
+    Dynamic(DynamicEvent),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrongEvent {
+    pub timestamp: DateTime<Utc>,
+    pub category: String,
+    pub value: f64,
+}
+
+/// The raw row behind an [`Event::Dynamic`]. Wrapped in a one-field struct
+/// rather than carrying a bare `serde_json::Value`, since internally-tagged
+/// enums (`#[serde(tag = "kind")]`) can only serialize newtype variants
+/// whose content is itself a map - a bare `Value` panics-as-error for any
+/// row whose raw payload is a JSON scalar or array.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DynamicEvent {
+    pub raw: serde_json::Value,
+}
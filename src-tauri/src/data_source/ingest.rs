@@ -0,0 +1,413 @@
+// Ingestion for the CSV/JSON data sources described in `data_source::types`.
+use crate::data_source::event::{DynamicEvent, Event, StrongEvent};
+use crate::data_source::types::{CsvConfig, JsonConfig, SourceConfiguration};
+use crate::error::{AppError, ErrorCategory, ErrorSeverity};
+use crate::{app_err, trace_err};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColumnType {
+    Timestamp,
+    Number,
+    String,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewResult {
+    pub events: Vec<Event>,
+    pub column_types: HashMap<String, ColumnType>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub row_count: usize,
+    pub parse_error_count: usize,
+    pub failed_mappings: Vec<String>,
+}
+
+/// Returns the configured source file's path, regardless of source type.
+pub fn file_path(config: &SourceConfiguration) -> &str {
+    match config {
+        SourceConfiguration::Csv(csv) => &csv.file_path,
+        SourceConfiguration::Json(json) => &json.file_path,
+    }
+}
+
+/// Parses every row of the data source into [`Event`]s.
+pub fn read_events(config: &SourceConfiguration) -> Result<Vec<Event>, AppError> {
+    Ok(read_rows(config, None)?
+        .into_iter()
+        .map(|row| row.event)
+        .collect())
+}
+
+/// Parses only the first `limit` rows, returning them alongside the column
+/// types inferred from what was actually parsed.
+pub fn preview_events(
+    config: &SourceConfiguration,
+    limit: usize,
+) -> Result<PreviewResult, AppError> {
+    let events: Vec<Event> = read_rows(config, Some(limit))?
+        .into_iter()
+        .map(|row| row.event)
+        .collect();
+    let column_types = infer_column_types(&events);
+    Ok(PreviewResult {
+        events,
+        column_types,
+    })
+}
+
+/// Scans the whole data source, reporting row/parse-error counts and which
+/// mapped fields failed to parse anywhere in the file.
+pub fn validate_events(config: &SourceConfiguration) -> Result<ValidationReport, AppError> {
+    let rows = read_rows(config, None)?;
+    let mut failed_mappings = HashSet::new();
+    let mut parse_error_count = 0;
+    for row in &rows {
+        if !row.failed_fields.is_empty() {
+            parse_error_count += 1;
+            failed_mappings.extend(row.failed_fields.iter().cloned());
+        }
+    }
+    Ok(ValidationReport {
+        row_count: rows.len(),
+        parse_error_count,
+        failed_mappings: failed_mappings.into_iter().collect(),
+    })
+}
+
+struct ParsedRow {
+    event: Event,
+    failed_fields: Vec<String>,
+}
+
+fn read_rows(
+    config: &SourceConfiguration,
+    limit: Option<usize>,
+) -> Result<Vec<ParsedRow>, AppError> {
+    match config {
+        SourceConfiguration::Csv(csv_config) => read_csv_rows(csv_config, limit),
+        SourceConfiguration::Json(json_config) => read_json_rows(json_config, limit),
+    }
+}
+
+fn read_csv_rows(config: &CsvConfig, limit: Option<usize>) -> Result<Vec<ParsedRow>, AppError> {
+    // TODO: honor `encoding` once a transcoding crate is wired in; UTF-8 is assumed for now.
+    let file = trace_err!(File::open(&config.file_path))?;
+    let delimiter = config.delimiter.as_bytes().first().copied().unwrap_or(b',');
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(config.has_headers)
+        .from_reader(BufReader::new(file));
+
+    let headers: Vec<String> = if config.has_headers {
+        trace_err!(reader.headers())?
+            .iter()
+            .map(|h| h.to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut rows = Vec::new();
+    for result in reader.records() {
+        if limit.is_some_and(|limit| rows.len() >= limit) {
+            break;
+        }
+        let record = trace_err!(result)?;
+        let row: HashMap<String, String> = if headers.is_empty() {
+            record
+                .iter()
+                .enumerate()
+                .map(|(i, value)| (i.to_string(), value.to_string()))
+                .collect()
+        } else {
+            headers
+                .iter()
+                .cloned()
+                .zip(record.iter().map(|value| value.to_string()))
+                .collect()
+        };
+        let raw = serde_json::to_value(&row).unwrap_or(serde_json::Value::Null);
+        rows.push(parse_row(
+            |column| row.get(column).cloned(),
+            &config.column_mappings,
+            raw,
+        ));
+    }
+    Ok(rows)
+}
+
+fn read_json_rows(config: &JsonConfig, limit: Option<usize>) -> Result<Vec<ParsedRow>, AppError> {
+    let contents = trace_err!(std::fs::read_to_string(&config.file_path))?;
+    let root: serde_json::Value = trace_err!(serde_json::from_str(&contents))?;
+
+    let array = match &config.root_path {
+        Some(pointer) => root.pointer(pointer).ok_or_else(|| {
+            app_err!(
+                format!("root_path '{}' not found in JSON document", pointer),
+                ErrorCategory::Processing,
+                ErrorSeverity::Error
+            )
+        })?,
+        None => &root,
+    };
+
+    let items = array.as_array().ok_or_else(|| {
+        app_err!(
+            "expected root_path to reference a JSON array".to_string(),
+            ErrorCategory::Processing,
+            ErrorSeverity::Error
+        )
+    })?;
+
+    let mut rows = Vec::new();
+    for item in items {
+        if limit.is_some_and(|limit| rows.len() >= limit) {
+            break;
+        }
+        let get = |column: &str| item.get(column).map(value_to_raw_string);
+        rows.push(parse_row(get, &config.mappings, item.clone()));
+    }
+    Ok(rows)
+}
+
+fn value_to_raw_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_row(
+    get: impl Fn(&str) -> Option<String>,
+    mappings: &HashMap<String, String>,
+    raw: serde_json::Value,
+) -> ParsedRow {
+    match try_build_strong_event(get, mappings) {
+        Ok(strong) => ParsedRow {
+            event: Event::TypeSafe(strong),
+            failed_fields: Vec::new(),
+        },
+        Err(failed_fields) => ParsedRow {
+            event: Event::Dynamic(DynamicEvent { raw }),
+            failed_fields,
+        },
+    }
+}
+
+/// Attempts to build a [`StrongEvent`] from a row, using `mappings` to find
+/// the source column for each of `timestamp`, `category` and `value`.
+/// Returns every field that was missing or failed to parse.
+fn try_build_strong_event(
+    get: impl Fn(&str) -> Option<String>,
+    mappings: &HashMap<String, String>,
+) -> Result<StrongEvent, Vec<String>> {
+    let mut failed = Vec::new();
+
+    let timestamp = mappings
+        .get("timestamp")
+        .and_then(|column| get(column))
+        .and_then(|raw| DateTime::parse_from_rfc3339(&raw).ok())
+        .map(|dt| dt.with_timezone(&Utc));
+    if timestamp.is_none() {
+        failed.push("timestamp".to_string());
+    }
+
+    let category = mappings.get("category").and_then(|column| get(column));
+    if category.is_none() {
+        failed.push("category".to_string());
+    }
+
+    let value = mappings
+        .get("value")
+        .and_then(|column| get(column))
+        .and_then(|raw| raw.parse::<f64>().ok());
+    if value.is_none() {
+        failed.push("value".to_string());
+    }
+
+    if failed.is_empty() {
+        Ok(StrongEvent {
+            timestamp: timestamp.unwrap(),
+            category: category.unwrap(),
+            value: value.unwrap(),
+        })
+    } else {
+        Err(failed)
+    }
+}
+
+fn infer_column_types(events: &[Event]) -> HashMap<String, ColumnType> {
+    let mut types = HashMap::new();
+    for event in events {
+        match event {
+            Event::TypeSafe(_) => {
+                types
+                    .entry("timestamp".to_string())
+                    .or_insert(ColumnType::Timestamp);
+                types
+                    .entry("category".to_string())
+                    .or_insert(ColumnType::String);
+                types
+                    .entry("value".to_string())
+                    .or_insert(ColumnType::Number);
+            }
+            Event::Dynamic(DynamicEvent {
+                raw: serde_json::Value::Object(fields),
+            }) => {
+                for (key, value) in fields {
+                    let inferred = match value {
+                        serde_json::Value::Number(_) => ColumnType::Number,
+                        serde_json::Value::String(s) if DateTime::parse_from_rfc3339(s).is_ok() => {
+                            ColumnType::Timestamp
+                        }
+                        serde_json::Value::String(_) => ColumnType::String,
+                        _ => ColumnType::Unknown,
+                    };
+                    types
+                        .entry(key.clone())
+                        .and_modify(|existing| {
+                            if *existing == ColumnType::Unknown {
+                                *existing = inferred;
+                            }
+                        })
+                        .or_insert(inferred);
+                }
+            }
+            Event::Dynamic(_) => {}
+        }
+    }
+    types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn mappings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_csv_rows_split_typed_and_dynamic() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("events.csv");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "ts,kind,amount").unwrap();
+        writeln!(file, "2024-01-01T00:00:00Z,click,1.5").unwrap();
+        writeln!(file, "not-a-date,click,1.5").unwrap();
+
+        let config = CsvConfig {
+            file_path: path.to_string_lossy().to_string(),
+            delimiter: ",".to_string(),
+            has_headers: true,
+            encoding: "utf-8".to_string(),
+            column_mappings: mappings(&[
+                ("timestamp", "ts"),
+                ("category", "kind"),
+                ("value", "amount"),
+            ]),
+        };
+
+        let events = read_events(&SourceConfiguration::Csv(config)).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Event::TypeSafe(_)));
+        assert!(matches!(events[1], Event::Dynamic(_)));
+    }
+
+    #[test]
+    fn test_json_root_path_and_preview_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("events.json");
+        std::fs::write(
+            &path,
+            r#"{"data": [
+                {"ts": "2024-01-01T00:00:00Z", "kind": "click", "amount": 1},
+                {"ts": "2024-01-02T00:00:00Z", "kind": "click", "amount": 2},
+                {"ts": "2024-01-03T00:00:00Z", "kind": "click", "amount": 3}
+            ]}"#,
+        )
+        .unwrap();
+
+        let config = JsonConfig {
+            file_path: path.to_string_lossy().to_string(),
+            root_path: Some("/data".to_string()),
+            mappings: mappings(&[
+                ("timestamp", "ts"),
+                ("category", "kind"),
+                ("value", "amount"),
+            ]),
+        };
+
+        let preview = preview_events(&SourceConfiguration::Json(config), 2).unwrap();
+        assert_eq!(preview.events.len(), 2);
+        assert_eq!(
+            preview.column_types.get("timestamp"),
+            Some(&ColumnType::Timestamp)
+        );
+    }
+
+    #[test]
+    fn test_validate_events_reports_failed_mappings() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("events.csv");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "ts,kind,amount").unwrap();
+        writeln!(file, "2024-01-01T00:00:00Z,click,1.5").unwrap();
+        writeln!(file, "garbage,click,not-a-number").unwrap();
+
+        let config = CsvConfig {
+            file_path: path.to_string_lossy().to_string(),
+            delimiter: ",".to_string(),
+            has_headers: true,
+            encoding: "utf-8".to_string(),
+            column_mappings: mappings(&[
+                ("timestamp", "ts"),
+                ("category", "kind"),
+                ("value", "amount"),
+            ]),
+        };
+
+        let report = validate_events(&SourceConfiguration::Csv(config)).unwrap();
+        assert_eq!(report.row_count, 2);
+        assert_eq!(report.parse_error_count, 1);
+        assert!(report.failed_mappings.contains(&"timestamp".to_string()));
+        assert!(report.failed_mappings.contains(&"value".to_string()));
+    }
+
+    #[test]
+    fn test_json_scalar_rows_serialize_as_dynamic_events() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("events.json");
+        std::fs::write(&path, r#"{"data": ["not an object", 42]}"#).unwrap();
+
+        let config = JsonConfig {
+            file_path: path.to_string_lossy().to_string(),
+            root_path: Some("/data".to_string()),
+            mappings: mappings(&[
+                ("timestamp", "ts"),
+                ("category", "kind"),
+                ("value", "amount"),
+            ]),
+        };
+
+        let events = read_events(&SourceConfiguration::Json(config)).unwrap();
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            assert!(matches!(event, Event::Dynamic(_)));
+            serde_json::to_value(event).expect("Dynamic events must always serialize");
+        }
+    }
+}
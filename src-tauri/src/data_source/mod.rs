@@ -0,0 +1,4 @@
+pub mod event;
+pub mod ingest;
+pub mod store;
+pub mod types;
@@ -0,0 +1,28 @@
+// In-memory registry of configured data sources, keyed by id.
+use crate::data_source::types::DataSource;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+fn store() -> &'static Mutex<HashMap<String, DataSource>> {
+    static STORE: OnceLock<Mutex<HashMap<String, DataSource>>> = OnceLock::new();
+    STORE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Inserts or replaces the data source under its own id.
+pub fn insert(source: DataSource) -> DataSource {
+    let mut sources = store().lock().unwrap();
+    sources.insert(source.id.clone(), source.clone());
+    source
+}
+
+pub fn list() -> Vec<DataSource> {
+    store().lock().unwrap().values().cloned().collect()
+}
+
+pub fn get(id: &str) -> Option<DataSource> {
+    store().lock().unwrap().get(id).cloned()
+}
+
+pub fn remove(id: &str) -> Option<DataSource> {
+    store().lock().unwrap().remove(id)
+}
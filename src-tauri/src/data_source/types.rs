@@ -54,4 +54,4 @@ pub struct DataSourceMetadata {
     pub last_validated: Option<String>,
     pub row_count: Option<usize>,
     pub file_size: Option<u64>,
-}
\ No newline at end of file
+}
@@ -1,15 +1,23 @@
 /// Error handling module for the Event Visualization application.
-/// 
+///
 /// This module provides a comprehensive error handling system with:
 /// - Categorized error types for different failure scenarios
 /// - Severity levels for error prioritization
 /// - Context preservation for debugging
 /// - Automatic sanitization of sensitive data
 /// - Recovery suggestions for common errors
+/// - Call-site trace chains that survive serialization to the frontend
+/// - Label-based retryable-error classification with backoff retry
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 use thiserror::Error;
 
+/// Well-known [`AppError`] labels, borrowed from the MongoDB driver's
+/// error-label model.
+pub const RETRYABLE_ERROR: &str = "RetryableError";
+pub const TRANSIENT_ERROR: &str = "TransientError";
+
 /// Categories of errors that can occur in the application
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorCategory {
@@ -32,6 +40,72 @@ pub enum ErrorSeverity {
     Critical,
 }
 
+/// A single call-site captured by `push_trace` as an `AppError` bubbles up
+/// through layers of the application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trace {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub fn_name: String,
+}
+
+/// Expands to the path of the enclosing function as a `&'static str`, used
+/// for [`Trace::fn_name`] by `app_err!` and `trace_err!`.
+///
+/// This mirrors `stdext::function_name!` without pulling in the crate: a
+/// zero-sized local function's `std::any::type_name` encodes the enclosing
+/// function's path, and we trim the trailing `::f`.
+#[macro_export]
+macro_rules! function_name {
+    () => {{
+        fn f() {}
+        fn __type_name_of<T>(_: T) -> &'static str {
+            std::any::type_name::<T>()
+        }
+        let name = __type_name_of(f);
+        &name[..name.len() - 3]
+    }};
+}
+
+/// Builds an [`AppError`] with a [`Trace`] for the construction site already
+/// recorded, so `error_chain()` has something to show even for the
+/// innermost error.
+#[macro_export]
+macro_rules! app_err {
+    ($message:expr, $category:expr, $severity:expr) => {{
+        let mut __err = $crate::error::AppError::new($message.to_string(), $category, $severity);
+        __err.push_trace($crate::error::Trace {
+            file: file!().to_string(),
+            line: line!(),
+            column: column!(),
+            fn_name: $crate::function_name!().to_string(),
+        });
+        __err
+    }};
+}
+
+/// Wraps a `Result<T, E>` (where `E: Into<AppError>`) and, on error, appends
+/// a [`Trace`] for *this* call site before returning it - so an error that
+/// bubbles up through several layers (e.g. a file read inside `ingest`, then
+/// the Tauri command that called it) accumulates one [`Trace`] per layer
+/// instead of `error_chain()` only ever showing the original conversion.
+#[macro_export]
+macro_rules! trace_err {
+    ($result:expr) => {{
+        $result.map_err(|err| {
+            let mut __err: $crate::error::AppError = err.into();
+            __err.push_trace($crate::error::Trace {
+                file: file!().to_string(),
+                line: line!(),
+                column: column!(),
+                fn_name: $crate::function_name!().to_string(),
+            });
+            __err
+        })
+    }};
+}
+
 #[derive(Debug, Error, Serialize, Deserialize, Clone)]
 pub struct AppError {
     message: String,
@@ -41,6 +115,8 @@ pub struct AppError {
     severity: ErrorSeverity,
     context: HashMap<String, String>,
     timestamp: std::time::SystemTime,
+    traces: Vec<Trace>,
+    labels: HashSet<String>,
 }
 
 impl std::fmt::Display for AppError {
@@ -51,12 +127,12 @@ impl std::fmt::Display for AppError {
 
 impl AppError {
     /// Creates a new AppError with the specified message, category, and severity.
-    /// 
+    ///
     /// # Arguments
     /// * `message` - The error message
     /// * `category` - The error category for classification
     /// * `severity` - The severity level of the error
-    /// 
+    ///
     /// # Example
     /// ```rust
     /// let error = AppError::new(
@@ -72,6 +148,8 @@ impl AppError {
             severity,
             context: HashMap::new(),
             timestamp: std::time::SystemTime::now(),
+            traces: Vec::new(),
+            labels: HashSet::new(),
         }
     }
 
@@ -82,7 +160,11 @@ impl AppError {
     }
 
     pub fn from_io_error(err: std::io::Error) -> Self {
-        Self::new(err.to_string(), ErrorCategory::FileSystem, ErrorSeverity::Error)
+        Self::new(
+            err.to_string(),
+            ErrorCategory::FileSystem,
+            ErrorSeverity::Error,
+        )
     }
 
     pub fn with_context(&mut self, key: &str, value: &str) -> &mut Self {
@@ -90,6 +172,36 @@ impl AppError {
         self
     }
 
+    /// Appends a call-site [`Trace`] as this error bubbles up through
+    /// another layer of the application.
+    pub fn push_trace(&mut self, trace: Trace) -> &mut Self {
+        self.traces.push(trace);
+        self
+    }
+
+    pub fn traces(&self) -> &[Trace] {
+        &self.traces
+    }
+
+    /// Attaches a well-known label (e.g. [`RETRYABLE_ERROR`]) to this error.
+    pub fn add_label(&mut self, label: &str) -> &mut Self {
+        self.labels.insert(label.to_string());
+        self
+    }
+
+    pub fn contains_label(&self, label: &str) -> bool {
+        self.labels.contains(label)
+    }
+
+    /// True when the caller should consider re-running the failed
+    /// operation: network-category errors are assumed transient, and any
+    /// error can be marked explicitly via [`Self::add_label`].
+    pub fn is_retryable(&self) -> bool {
+        self.category == ErrorCategory::Network
+            || self.contains_label(RETRYABLE_ERROR)
+            || self.contains_label(TRANSIENT_ERROR)
+    }
+
     pub fn message(&self) -> &str {
         &self.message
     }
@@ -124,8 +236,22 @@ impl AppError {
         }
     }
 
+    /// Formats each recorded [`Trace`] as `"file:line (fn): message"`,
+    /// ordered from the innermost call site (where the error was first
+    /// raised) to the outermost (where it was last re-raised).
     pub fn error_chain(&self) -> Vec<String> {
-        vec![self.message.clone()]
+        if self.traces.is_empty() {
+            return vec![self.message.clone()];
+        }
+        self.traces
+            .iter()
+            .map(|trace| {
+                format!(
+                    "{}:{} ({}): {}",
+                    trace.file, trace.line, trace.fn_name, self.message
+                )
+            })
+            .collect()
     }
 
     pub fn sanitized_message(&self) -> String {
@@ -140,13 +266,15 @@ impl AppError {
                 s
             },
         );
-        
+
         // Simple sanitization - replace anything after password=, api_key=, etc.
         let patterns = vec!["password=", "api_key=", "secret=", "token="];
         for pattern in patterns {
             if let Some(idx) = sanitized.find(pattern) {
                 let start = idx + pattern.len();
-                if let Some(end_idx) = sanitized[start..].find(|c: char| c.is_whitespace() || c == ',' || c == ';') {
+                if let Some(end_idx) =
+                    sanitized[start..].find(|c: char| c.is_whitespace() || c == ',' || c == ';')
+                {
                     let end = start + end_idx;
                     sanitized.replace_range(start..end, "***");
                 } else {
@@ -167,7 +295,10 @@ impl AppError {
         let lower = message.to_lowercase();
         if lower.contains("network") || lower.contains("connection") || lower.contains("timeout") {
             ErrorCategory::Network
-        } else if lower.contains("invalid") || lower.contains("validation") || lower.contains("required") {
+        } else if lower.contains("invalid")
+            || lower.contains("validation")
+            || lower.contains("required")
+        {
             ErrorCategory::Validation
         } else if lower.contains("file") || lower.contains("not found") {
             ErrorCategory::FileSystem
@@ -192,6 +323,88 @@ impl AppError {
     }
 }
 
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        // These kinds are the ones a flaky file handle or network-backed
+        // mount would plausibly clear up on its own; anything else (e.g.
+        // NotFound, PermissionDenied) is permanent and shouldn't be retried.
+        let retryable = matches!(
+            err.kind(),
+            std::io::ErrorKind::Interrupted
+                | std::io::ErrorKind::WouldBlock
+                | std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::BrokenPipe
+        );
+        let mut app_err = Self::from_io_error(err);
+        if retryable {
+            app_err.add_label(RETRYABLE_ERROR);
+        }
+        app_err
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::new(
+            err.to_string(),
+            ErrorCategory::Processing,
+            ErrorSeverity::Error,
+        )
+    }
+}
+
+impl From<csv::Error> for AppError {
+    fn from(err: csv::Error) -> Self {
+        Self::new(
+            err.to_string(),
+            ErrorCategory::Processing,
+            ErrorSeverity::Error,
+        )
+    }
+}
+
+fn jitter_millis() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_millis() as u64 % 100)
+        .unwrap_or(0)
+}
+
+/// Re-runs `op` while it keeps failing with a [`AppError::is_retryable`]
+/// error, up to `max_attempts` tries, waiting an exponentially growing
+/// (plus jitter) delay between attempts. The final error gets a
+/// `retry_attempts` context entry so the frontend can show how hard we
+/// tried before giving up.
+pub async fn retry_with_backoff<F, Fut, T>(
+    mut op: F,
+    max_attempts: u32,
+    base_delay: Duration,
+) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, AppError>>,
+{
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(mut err) => {
+                if attempt >= max_attempts || !err.is_retryable() {
+                    err.with_context("retry_attempts", &attempt.to_string());
+                    return Err(err);
+                }
+                let exponent = attempt.saturating_sub(1).min(16);
+                let backoff = base_delay.saturating_mul(1u32 << exponent);
+                tokio::time::sleep(backoff + Duration::from_millis(jitter_millis())).await;
+            }
+        }
+    }
+}
+
 pub struct ErrorMetrics {
     total_errors: usize,
     errors_by_category: HashMap<ErrorCategory, usize>,
@@ -209,8 +422,14 @@ impl ErrorMetrics {
 
     pub fn record_error(&mut self, error: &AppError) {
         self.total_errors += 1;
-        *self.errors_by_category.entry(error.category.clone()).or_insert(0) += 1;
-        *self.errors_by_severity.entry(error.severity.clone()).or_insert(0) += 1;
+        *self
+            .errors_by_category
+            .entry(error.category.clone())
+            .or_insert(0) += 1;
+        *self
+            .errors_by_severity
+            .entry(error.severity.clone())
+            .or_insert(0) += 1;
     }
 
     pub fn get_stats(&self) -> ErrorStats {
@@ -251,4 +470,123 @@ mod tests {
         assert!(!sanitized.contains("secret123"));
         assert!(sanitized.contains("***"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_push_trace_builds_error_chain() {
+        let mut error = AppError::new(
+            "disk read failed".to_string(),
+            ErrorCategory::FileSystem,
+            ErrorSeverity::Error,
+        );
+        error.push_trace(Trace {
+            file: "inner.rs".to_string(),
+            line: 10,
+            column: 5,
+            fn_name: "read_chunk".to_string(),
+        });
+        error.push_trace(Trace {
+            file: "outer.rs".to_string(),
+            line: 42,
+            column: 9,
+            fn_name: "load_file".to_string(),
+        });
+
+        let chain = error.error_chain();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0], "inner.rs:10 (read_chunk): disk read failed");
+        assert_eq!(chain[1], "outer.rs:42 (load_file): disk read failed");
+    }
+
+    #[test]
+    fn test_app_err_macro_records_call_site() {
+        let error = app_err!("boom", ErrorCategory::System, ErrorSeverity::Error);
+        assert_eq!(error.traces().len(), 1);
+        // Anchored (`ends_with`, not `.contains`) so a dangling "::" left by an off-by-two
+        // trim in `function_name!` would fail this assertion.
+        assert!(error.traces()[0]
+            .fn_name
+            .ends_with("test_app_err_macro_records_call_site"));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        let network_error = AppError::new(
+            "Connection timed out".to_string(),
+            ErrorCategory::Network,
+            ErrorSeverity::Error,
+        );
+        assert!(network_error.is_retryable());
+
+        let mut labeled_error = AppError::new(
+            "Upstream hiccup".to_string(),
+            ErrorCategory::Unknown,
+            ErrorSeverity::Error,
+        );
+        assert!(!labeled_error.is_retryable());
+        labeled_error.add_label(RETRYABLE_ERROR);
+        assert!(labeled_error.is_retryable());
+        assert!(labeled_error.contains_label(RETRYABLE_ERROR));
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_recovers_then_succeeds() {
+        let attempts = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let attempts_clone = attempts.clone();
+
+        let result = retry_with_backoff(
+            move || {
+                let attempts = attempts_clone.clone();
+                async move {
+                    let current = attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    if current < 2 {
+                        let mut err = AppError::new(
+                            "Network connection failed".to_string(),
+                            ErrorCategory::Network,
+                            ErrorSeverity::Error,
+                        );
+                        err.add_label(RETRYABLE_ERROR);
+                        Err(err)
+                    } else {
+                        Ok("recovered")
+                    }
+                }
+            },
+            5,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "recovered");
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_gives_up_on_non_retryable() {
+        let result: Result<(), AppError> = retry_with_backoff(
+            || async {
+                Err(AppError::new(
+                    "Invalid input".to_string(),
+                    ErrorCategory::Validation,
+                    ErrorSeverity::Warning,
+                ))
+            },
+            5,
+            Duration::from_millis(1),
+        )
+        .await;
+
+        let err = result.unwrap_err();
+        assert_eq!(err.context().get("retry_attempts"), Some(&"1".to_string()));
+    }
+
+    #[test]
+    fn test_from_conversions_preserve_category() {
+        let io_err: AppError = std::io::Error::new(std::io::ErrorKind::NotFound, "missing").into();
+        assert_eq!(io_err.category(), &ErrorCategory::FileSystem);
+
+        let json_err: AppError = serde_json::from_str::<serde_json::Value>("{not json")
+            .unwrap_err()
+            .into();
+        assert_eq!(json_err.category(), &ErrorCategory::Processing);
+    }
+}
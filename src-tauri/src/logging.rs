@@ -1,10 +1,19 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::Subscriber;
+use tracing_subscriber::{
+    fmt, layer::Context, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LogLevel {
     Debug,
     Info,
@@ -29,57 +38,393 @@ pub trait Logger: Send + Sync {
     fn log(&self, level: LogLevel, message: &str);
 }
 
+/// A single entry retained in the in-memory log buffer, returned to the
+/// frontend via the `get_logs` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub timestamp: DateTime<Utc>,
+    pub level: LogLevel,
+    pub module: Option<String>,
+    pub message: String,
+}
+
+/// Default retention window for buffered log records, in seconds.
+pub const DEFAULT_LOG_RETENTION_SECS: u64 = 86_400;
+
+/// Default rotation thresholds for the application's own log file.
+const DEFAULT_LOG_MAX_SIZE_BYTES: usize = 10 * 1024 * 1024;
+const DEFAULT_LOG_MAX_FILES: usize = 5;
+
+fn default_limit() -> u32 {
+    100
+}
+
+/// Query parameters for filtering the in-memory log buffer, used by the
+/// `get_logs` Tauri command.
+#[derive(Debug, Clone)]
+pub struct RecordFilter {
+    pub level: Option<LogLevel>,
+    pub module: Option<String>,
+    pub regex: Option<Regex>,
+    pub not_before: Option<DateTime<Utc>>,
+    pub limit: u32,
+}
+
+impl Default for RecordFilter {
+    fn default() -> Self {
+        Self {
+            level: None,
+            module: None,
+            regex: None,
+            not_before: None,
+            limit: default_limit(),
+        }
+    }
+}
+
+impl RecordFilter {
+    /// Walks `buffer` newest-first, collecting up to `limit` records that
+    /// pass every configured predicate.
+    pub fn apply(&self, buffer: &[Arc<LogRecord>]) -> Vec<Arc<LogRecord>> {
+        let mut matches = Vec::new();
+        for record in buffer.iter().rev() {
+            if matches.len() >= self.limit as usize {
+                break;
+            }
+            if let Some(level) = &self.level {
+                if record.level < *level {
+                    continue;
+                }
+            }
+            if let Some(module) = &self.module {
+                match &record.module {
+                    Some(m) if m.contains(module.as_str()) => {}
+                    _ => continue,
+                }
+            }
+            if let Some(regex) = &self.regex {
+                if !regex.is_match(&record.message) {
+                    continue;
+                }
+            }
+            if let Some(not_before) = &self.not_before {
+                if record.timestamp < *not_before {
+                    continue;
+                }
+            }
+            matches.push(record.clone());
+        }
+        matches
+    }
+}
+
+// `Regex` has no native `Deserialize` impl, so the wire format carries the
+// pattern as a string and we compile it here.
+impl<'de> Deserialize<'de> for RecordFilter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RecordFilterDto {
+            level: Option<LogLevel>,
+            module: Option<String>,
+            regex: Option<String>,
+            not_before: Option<DateTime<Utc>>,
+            #[serde(default = "default_limit")]
+            limit: u32,
+        }
+
+        let dto = RecordFilterDto::deserialize(deserializer)?;
+        let regex = dto
+            .regex
+            .map(|pattern| Regex::new(&pattern).map_err(serde::de::Error::custom))
+            .transpose()?;
+
+        Ok(Self {
+            level: dto.level,
+            module: dto.module,
+            regex,
+            not_before: dto.not_before,
+            limit: dto.limit,
+        })
+    }
+}
+
+fn log_buffer() -> &'static Mutex<Vec<Arc<LogRecord>>> {
+    static LOG_BUFFER: OnceLock<Mutex<Vec<Arc<LogRecord>>>> = OnceLock::new();
+    LOG_BUFFER.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+fn push_to_buffer(level: LogLevel, module: Option<&str>, message: &str) {
+    let record = Arc::new(LogRecord {
+        timestamp: Utc::now(),
+        level,
+        module: module.map(|m| m.to_string()),
+        message: message.to_string(),
+    });
+    if let Ok(mut buffer) = log_buffer().lock() {
+        buffer.push(record);
+    }
+}
+
+/// Runs `filter` against the current buffer and returns the matching
+/// records, newest-first.
+pub fn query_logs(filter: &RecordFilter) -> Vec<LogRecord> {
+    let buffer = log_buffer().lock().unwrap();
+    filter
+        .apply(&buffer)
+        .into_iter()
+        .map(|record| (*record).clone())
+        .collect()
+}
+
+fn prune_log_buffer(retention: Duration) {
+    let cutoff = Utc::now()
+        - chrono::Duration::from_std(retention).unwrap_or_else(|_| chrono::Duration::zero());
+    if let Ok(mut buffer) = log_buffer().lock() {
+        buffer.retain(|record| record.timestamp >= cutoff);
+    }
+}
+
+/// Spawns a background task that prunes log records older than `retention`
+/// roughly once a minute. Intended to be called once from app setup.
+pub fn start_log_pruning_task(retention: Duration) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            prune_log_buffer(retention);
+        }
+    });
+}
+
+static CONSOLE_LOG_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns off the colorized console layer so the in-memory buffer (and the
+/// file sink) aren't duplicating output that's already visible elsewhere.
+/// The flag is read only by [`init_logging`], so calling this afterwards
+/// leaves an already-installed console layer in place.
+pub fn disable_console_log() {
+    CONSOLE_LOG_ENABLED.store(false, Ordering::Relaxed);
+}
+
+fn console_log_enabled() -> bool {
+    CONSOLE_LOG_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Rotation policy for a [`FileSink`]: roll the file once it would exceed
+/// `max_size` bytes, keeping at most `max_files` rotated segments.
+struct RotationPolicy {
+    base_path: PathBuf,
+    max_size: u64,
+    max_files: usize,
+}
+
+/// The file handle a [`FileLogger`] writes through, plus its optional
+/// rotation policy. Lives behind a single `Mutex` so rotating and writing
+/// happen as one atomic step and concurrent loggers never interleave or
+/// lose a line across a rename.
+struct FileSink {
+    file: File,
+    rotation: Option<RotationPolicy>,
+}
+
+impl FileSink {
+    fn write_entry(&mut self, entry: &str) -> io::Result<()> {
+        if let Some(rotation) = &self.rotation {
+            let current_len = self.file.metadata()?.len();
+            if current_len > 0 && current_len + entry.len() as u64 > rotation.max_size {
+                self.file.flush()?;
+                rotate(&rotation.base_path, rotation.max_files)?;
+                self.file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&rotation.base_path)?;
+            }
+        }
+        self.file.write_all(entry.as_bytes())
+    }
+}
+
+/// Renames `base_path` -> `base_path.1`, shifting any existing `.1` -> `.2`
+/// and so on, dropping whatever would land beyond `max_files`.
+fn rotate(base_path: &std::path::Path, max_files: usize) -> io::Result<()> {
+    if max_files == 0 {
+        let _ = std::fs::remove_file(base_path);
+        return Ok(());
+    }
+
+    let oldest = rotated_path(base_path, max_files);
+    if oldest.exists() {
+        std::fs::remove_file(&oldest)?;
+    }
+    for n in (1..max_files).rev() {
+        let from = rotated_path(base_path, n);
+        if from.exists() {
+            std::fs::rename(&from, rotated_path(base_path, n + 1))?;
+        }
+    }
+    if base_path.exists() {
+        std::fs::rename(base_path, rotated_path(base_path, 1))?;
+    }
+    Ok(())
+}
+
+fn rotated_path(base_path: &std::path::Path, n: usize) -> PathBuf {
+    let mut name = base_path.as_os_str().to_os_string();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
 pub struct FileLogger {
-    file: Arc<Mutex<File>>,
+    sink: Arc<Mutex<FileSink>>,
     min_level: LogLevel,
+    module: Option<String>,
 }
 
 impl FileLogger {
     pub fn new(path: PathBuf, min_level: LogLevel) -> io::Result<Self> {
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(path)?;
-        
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
         Ok(Self {
-            file: Arc::new(Mutex::new(file)),
+            sink: Arc::new(Mutex::new(FileSink {
+                file,
+                rotation: None,
+            })),
             min_level,
+            module: None,
         })
     }
 
+    /// Tags every record this logger produces with `module`, so buffered
+    /// entries can be filtered by their source.
+    pub fn with_module(mut self, module: &str) -> Self {
+        self.module = Some(module.to_string());
+        self
+    }
+
+    /// Rolls the log file once appending an entry would push it past
+    /// `max_size` bytes, keeping up to `max_files` rotated segments
+    /// (`path`, `path.1`, `path.2`, ...).
     pub fn with_rotation(
         path: PathBuf,
         min_level: LogLevel,
-        _max_size: usize,
-        _max_files: usize,
+        max_size: usize,
+        max_files: usize,
     ) -> io::Result<Self> {
-        // Simplified version - just create a regular file logger
-        Self::new(path, min_level)
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        Ok(Self {
+            sink: Arc::new(Mutex::new(FileSink {
+                file,
+                rotation: Some(RotationPolicy {
+                    base_path: path,
+                    max_size: max_size as u64,
+                    max_files,
+                }),
+            })),
+            min_level,
+            module: None,
+        })
     }
 
     pub fn flush(&self) -> io::Result<()> {
-        self.file.lock().unwrap().flush()
+        self.sink.lock().unwrap().file.flush()
     }
 }
 
 impl Logger for FileLogger {
     fn log(&self, level: LogLevel, message: &str) {
+        self.log_with_module(level, None, message);
+    }
+}
+
+impl FileLogger {
+    /// Same as [`Logger::log`], but `module` (when given) is buffered
+    /// instead of the logger's own `self.module`, so a per-call-site module
+    /// (e.g. a tracing event's `target`) can be recorded without having to
+    /// build a dedicated `FileLogger` per module.
+    fn log_with_module(&self, level: LogLevel, module: Option<&str>, message: &str) {
         if level < self.min_level {
             return;
         }
 
+        push_to_buffer(level.clone(), module.or(self.module.as_deref()), message);
+
         let timestamp = chrono::Utc::now().to_rfc3339();
-        let log_entry = format!("[{}] [{}] {}\n", timestamp, level, message);
-        
-        if let Ok(mut file) = self.file.lock() {
-            let _ = file.write_all(log_entry.as_bytes());
+        let log_entry = format!(
+            "[{}] [{}] {}\n",
+            timestamp,
+            level,
+            strip_ansi_codes(message)
+        );
+
+        if let Ok(mut sink) = self.sink.lock() {
+            let _ = sink.write_entry(&log_entry);
+        }
+    }
+
+    /// Writes `line` to the file with ANSI codes stripped (plus a trailing newline), bypassing
+    /// the `[ts] [LEVEL] message` formatting `log` applies. Used by
+    /// [`StructuredLogger`] for JSON-formatted entries, which must stay
+    /// valid NDJSON. `message` is the plain log text buffered for
+    /// `get_logs`, kept separate from `line` so the buffer never ends up
+    /// holding a serialized JSON document as its message. `module` (when
+    /// given) overrides `self.module` in the buffered record, same as
+    /// [`Self::log_with_module`].
+    fn write_raw(&self, level: LogLevel, module: Option<&str>, message: &str, line: &str) {
+        if level < self.min_level {
+            return;
+        }
+
+        push_to_buffer(level, module.or(self.module.as_deref()), message);
+
+        if let Ok(mut sink) = self.sink.lock() {
+            let entry = format!("{}\n", strip_ansi_codes(line));
+            let _ = sink.write_entry(&entry);
         }
     }
 }
 
+/// Strips ANSI escape sequences so the file sink never ends up with color
+/// codes a colorized console layer might emit, keeping on-disk NDJSON valid.
+fn strip_ansi_codes(input: &str) -> std::borrow::Cow<'_, str> {
+    if !input.contains('\u{1b}') {
+        return std::borrow::Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        output.push(c);
+    }
+    std::borrow::Cow::Owned(output)
+}
+
+/// Output format for [`StructuredLogger::log_structured`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `message key=value key=value ...`, the original behavior.
+    Text,
+    /// A single NDJSON line: `{"ts": ..., "level": ..., "msg": ..., ...}`,
+    /// merging the persistent context under the per-call fields.
+    Json,
+}
+
 pub struct StructuredLogger {
     file_logger: FileLogger,
     context: Arc<Mutex<HashMap<String, String>>>,
+    format: LogFormat,
 }
 
 use std::collections::HashMap;
@@ -89,9 +434,33 @@ impl StructuredLogger {
         Ok(Self {
             file_logger: FileLogger::new(path, min_level)?,
             context: Arc::new(Mutex::new(HashMap::new())),
+            format: LogFormat::Text,
+        })
+    }
+
+    /// Same as [`Self::new`], but the underlying file rolls once an entry
+    /// would push it past `max_size` bytes, keeping up to `max_files`
+    /// rotated segments. See [`FileLogger::with_rotation`].
+    pub fn with_rotation(
+        path: PathBuf,
+        min_level: LogLevel,
+        max_size: usize,
+        max_files: usize,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            file_logger: FileLogger::with_rotation(path, min_level, max_size, max_files)?,
+            context: Arc::new(Mutex::new(HashMap::new())),
+            format: LogFormat::Text,
         })
     }
 
+    /// Selects the output format [`Self::log_structured`] writes entries in;
+    /// [`Self::log`] always writes the plain `[ts] [LEVEL] message` form.
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     pub fn set_context(&mut self, key: &str, value: &str) {
         if let Ok(mut ctx) = self.context.lock() {
             ctx.insert(key.to_string(), value.to_string());
@@ -102,12 +471,60 @@ impl StructuredLogger {
         self.file_logger.log(level, message);
     }
 
-    pub fn log_structured(&mut self, level: LogLevel, message: &str, fields: &[(&str, &str)]) {
-        let mut log_message = format!("{}", message);
+    /// `module`, when given, is buffered as the record's `module` (e.g. a
+    /// tracing event's `target`), letting `RecordFilter.module` match
+    /// entries the underlying `FileLogger` wasn't itself scoped to.
+    pub fn log_structured(
+        &mut self,
+        level: LogLevel,
+        message: &str,
+        module: Option<&str>,
+        fields: &[(&str, &str)],
+    ) {
+        match self.format {
+            LogFormat::Text => {
+                let mut log_message = message.to_string();
+                for (key, value) in fields {
+                    log_message.push_str(&format!(" {}={}", key, value));
+                }
+                self.file_logger.log_with_module(level, module, &log_message);
+            }
+            LogFormat::Json => {
+                let line = self.render_json_line(level.clone(), message, fields);
+                self.file_logger.write_raw(level, module, message, &line);
+            }
+        }
+    }
+
+    fn render_json_line(&self, level: LogLevel, message: &str, fields: &[(&str, &str)]) -> String {
+        let mut entry = serde_json::Map::new();
+        entry.insert(
+            "ts".to_string(),
+            serde_json::Value::String(Utc::now().to_rfc3339()),
+        );
+        entry.insert(
+            "level".to_string(),
+            serde_json::Value::String(level.to_string()),
+        );
+        entry.insert(
+            "msg".to_string(),
+            serde_json::Value::String(message.to_string()),
+        );
+
+        if let Ok(ctx) = self.context.lock() {
+            for (key, value) in ctx.iter() {
+                entry.insert(key.clone(), serde_json::Value::String(value.clone()));
+            }
+        }
         for (key, value) in fields {
-            log_message.push_str(&format!(" {}={}", key, value));
+            entry.insert(
+                key.to_string(),
+                serde_json::Value::String(value.to_string()),
+            );
         }
-        self.file_logger.log(level, &log_message);
+
+        serde_json::to_string(&serde_json::Value::Object(entry))
+            .unwrap_or_else(|_| message.to_string())
     }
 
     pub fn flush(&self) -> io::Result<()> {
@@ -115,14 +532,103 @@ impl StructuredLogger {
     }
 }
 
+/// Extracts the `message` field tracing attaches to every `info!`/`warn!`/
+/// etc. call, so it can be forwarded to a [`StructuredLogger`] as plain text.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        }
+    }
+}
+
+fn tracing_level_to_log_level(level: tracing::Level) -> LogLevel {
+    match level {
+        tracing::Level::TRACE | tracing::Level::DEBUG => LogLevel::Debug,
+        tracing::Level::INFO => LogLevel::Info,
+        tracing::Level::WARN => LogLevel::Warn,
+        tracing::Level::ERROR => LogLevel::Error,
+    }
+}
+
+/// A `tracing_subscriber` layer that is the one place real application log
+/// calls (`tracing::info!` and friends, as used throughout `main.rs` and the
+/// command handlers) actually reach the custom [`Logger`] stack. Without
+/// this layer, `get_logs` only ever sees whatever a test pushed directly
+/// against a `FileLogger`/`StructuredLogger` - never anything the running
+/// app itself logged.
+struct AppLogLayer {
+    logger: Mutex<StructuredLogger>,
+}
+
+impl AppLogLayer {
+    fn new(logger: StructuredLogger) -> Self {
+        Self {
+            logger: Mutex::new(logger),
+        }
+    }
+}
+
+impl<S> Layer<S> for AppLogLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let level = tracing_level_to_log_level(*event.metadata().level());
+
+        if let Ok(mut logger) = self.logger.lock() {
+            let target = event.metadata().target();
+            logger.log_structured(level, &visitor.message, Some(target), &[("target", target)]);
+        }
+    }
+}
+
+/// Builds and installs the global `tracing` subscriber used by the running
+/// application: an optional colorized console layer (left out when
+/// [`disable_console_log`] was called before this) plus an [`AppLogLayer`] that writes
+/// every event through a [`StructuredLogger`], so both the in-memory ring
+/// buffer `get_logs` reads from and the on-disk log file reflect what the
+/// app actually did.
 pub fn init_logging() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new("info"));
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
 
-    tracing_subscriber::registry()
-        .with(filter)
-        .with(fmt::layer().with_target(false))
-        .init();
+    // TODO: resolve this via Tauri's app_log_dir once an app handle is
+    // available this early in startup.
+    let log_path = std::env::temp_dir().join("event_viz.log");
+    let structured_logger = StructuredLogger::with_rotation(
+        log_path,
+        LogLevel::Debug,
+        DEFAULT_LOG_MAX_SIZE_BYTES,
+        DEFAULT_LOG_MAX_FILES,
+    )?
+    .with_format(LogFormat::Json);
+    let app_log_layer = AppLogLayer::new(structured_logger);
+
+    if console_log_enabled() {
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(
+                fmt::layer()
+                    .with_target(false)
+                    .with_thread_ids(true)
+                    .with_file(true)
+                    .with_line_number(true),
+            )
+            .with(app_log_layer)
+            .init();
+    } else {
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(app_log_layer)
+            .init();
+    }
 
     tracing::info!("Logging initialized");
     Ok(())
@@ -145,7 +651,7 @@ mod tests {
     fn test_file_logger_creation() {
         let temp_dir = TempDir::new().unwrap();
         let log_path = temp_dir.path().join("test.log");
-        
+
         let logger = FileLogger::new(log_path.clone(), LogLevel::Info).unwrap();
         logger.log(LogLevel::Info, "Test log message");
         logger.flush().unwrap();
@@ -154,4 +660,173 @@ mod tests {
         assert!(log_content.contains("Test log message"));
         assert!(log_content.contains("[INFO]"));
     }
-}
\ No newline at end of file
+
+    fn record(level: LogLevel, module: Option<&str>, message: &str) -> Arc<LogRecord> {
+        Arc::new(LogRecord {
+            timestamp: Utc::now(),
+            level,
+            module: module.map(|m| m.to_string()),
+            message: message.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_record_filter_respects_level_and_limit() {
+        let buffer = vec![
+            record(LogLevel::Debug, None, "debug one"),
+            record(LogLevel::Info, None, "info one"),
+            record(LogLevel::Error, None, "error one"),
+            record(LogLevel::Error, None, "error two"),
+        ];
+
+        let filter = RecordFilter {
+            level: Some(LogLevel::Info),
+            limit: 2,
+            ..RecordFilter::default()
+        };
+
+        let matches = filter.apply(&buffer);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].message, "error two");
+        assert_eq!(matches[1].message, "error one");
+    }
+
+    #[test]
+    fn test_record_filter_module_and_regex() {
+        let buffer = vec![
+            record(LogLevel::Info, Some("network"), "connected to peer"),
+            record(LogLevel::Info, Some("storage"), "connected to disk"),
+        ];
+
+        let filter = RecordFilter {
+            module: Some("network".to_string()),
+            regex: Some(Regex::new("peer").unwrap()),
+            ..RecordFilter::default()
+        };
+
+        let matches = filter.apply(&buffer);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].module.as_deref(), Some("network"));
+    }
+
+    #[test]
+    fn test_structured_logger_json_format_is_valid_ndjson() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("structured.log");
+
+        let mut logger = StructuredLogger::new(log_path.clone(), LogLevel::Info)
+            .unwrap()
+            .with_format(LogFormat::Json);
+        logger.set_context("service", "ingest");
+        logger.log_structured(LogLevel::Info, "row parsed", None, &[("rows", "3")]);
+        logger.flush().unwrap();
+
+        let contents = std::fs::read_to_string(log_path).unwrap();
+        let line = contents.lines().next().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+
+        assert_eq!(parsed["msg"], "row parsed");
+        assert_eq!(parsed["level"], "INFO");
+        assert_eq!(parsed["service"], "ingest");
+        assert_eq!(parsed["rows"], "3");
+    }
+
+    #[test]
+    fn test_structured_logger_json_format_buffers_plain_message() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("structured.log");
+
+        let mut logger = StructuredLogger::new(log_path, LogLevel::Info)
+            .unwrap()
+            .with_format(LogFormat::Json);
+        logger.set_context("service", "ingest");
+        logger.log_structured(LogLevel::Info, "row parsed", None, &[("rows", "3")]);
+
+        let filter = RecordFilter {
+            regex: Some(Regex::new("^row parsed$").unwrap()),
+            ..RecordFilter::default()
+        };
+        let matches = query_logs(&filter);
+        assert!(matches.iter().all(|m| m.message == "row parsed"));
+        assert!(!matches.is_empty());
+    }
+
+    #[test]
+    fn test_log_structured_threads_explicit_module_into_buffer() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("structured.log");
+
+        let mut logger = StructuredLogger::new(log_path, LogLevel::Info)
+            .unwrap()
+            .with_format(LogFormat::Json);
+        logger.log_structured(
+            LogLevel::Info,
+            "connected to peer",
+            Some("event_viz::network"),
+            &[("target", "event_viz::network")],
+        );
+
+        let filter = RecordFilter {
+            regex: Some(Regex::new("^connected to peer$").unwrap()),
+            module: Some("network".to_string()),
+            ..RecordFilter::default()
+        };
+        let matches = query_logs(&filter);
+        assert!(!matches.is_empty());
+        assert!(matches
+            .iter()
+            .all(|m| m.module.as_deref() == Some("event_viz::network")));
+    }
+
+    #[test]
+    fn test_structured_logger_json_fields_override_context() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("structured.log");
+
+        let mut logger = StructuredLogger::new(log_path, LogLevel::Info)
+            .unwrap()
+            .with_format(LogFormat::Json);
+        logger.set_context("rows", "0");
+        let line = logger.render_json_line(LogLevel::Info, "done", &[("rows", "5")]);
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+
+        assert_eq!(parsed["rows"], "5");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes() {
+        let colored = "\u{1b}[31merror\u{1b}[0m: boom";
+        assert_eq!(strip_ansi_codes(colored), "error: boom");
+    }
+
+    #[test]
+    fn test_rotation_rolls_once_max_size_exceeded() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("app.log");
+
+        let logger = FileLogger::with_rotation(log_path.clone(), LogLevel::Info, 40, 2).unwrap();
+        logger.log(LogLevel::Info, "first message");
+        logger.log(LogLevel::Info, "second message");
+        logger.log(LogLevel::Info, "third message");
+        logger.flush().unwrap();
+
+        assert!(log_path.exists());
+        let rotated_once = temp_dir.path().join("app.log.1");
+        assert!(rotated_once.exists());
+    }
+
+    #[test]
+    fn test_rotation_drops_segments_beyond_max_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("app.log");
+
+        let logger = FileLogger::with_rotation(log_path.clone(), LogLevel::Info, 20, 1).unwrap();
+        for i in 0..5 {
+            logger.log(LogLevel::Info, &format!("message number {}", i));
+        }
+        logger.flush().unwrap();
+
+        assert!(temp_dir.path().join("app.log.1").exists());
+        assert!(!temp_dir.path().join("app.log.2").exists());
+    }
+}
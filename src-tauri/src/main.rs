@@ -1,17 +1,22 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod error;
-mod logging;
 mod commands;
 mod data_source;
+mod error;
+mod logging;
 
 #[cfg(test)]
 mod tests;
 
+use commands::data_source::{
+    add_data_source, list_data_sources, preview_data_source, remove_data_source,
+    validate_data_source,
+};
+use commands::logging::get_logs;
 use error::{AppError, ErrorCategory, ErrorSeverity};
-use tracing::{info, Level};
-use tracing_subscriber;
+use std::time::Duration;
+use tracing::info;
 
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
@@ -23,13 +28,13 @@ fn greet(name: &str) -> String {
 #[tauri::command]
 async fn get_app_info() -> Result<serde_json::Value, String> {
     info!("App info requested");
-    
+
     let info = serde_json::json!({
         "name": "Event Viz",
         "version": "0.1.0",
         "description": "Event visualization application built with Tauri and React"
     });
-    
+
     Ok(info)
 }
 
@@ -61,18 +66,15 @@ async fn validate_input(input: String) -> Result<String, AppError> {
     Ok(format!("Valid input: {}", input))
 }
 
-fn setup_logging() {
-    tracing_subscriber::fmt()
-        .with_max_level(Level::INFO)
-        .with_target(false)
-        .with_thread_ids(true)
-        .with_file(true)
-        .with_line_number(true)
-        .init();
-}
-
 fn main() {
-    setup_logging();
+    // Release builds hide the console window (see the windows_subsystem
+    // attribute above), so the in-memory buffer and log file are the only
+    // sinks a user can actually inspect - the colorized console layer would
+    // just be wasted work.
+    if !cfg!(debug_assertions) {
+        logging::disable_console_log();
+    }
+    logging::init_logging().expect("failed to initialize logging");
     info!("Starting Event Viz application");
 
     tauri::Builder::default()
@@ -81,12 +83,21 @@ fn main() {
             greet,
             get_app_info,
             test_error_handling,
-            validate_input
+            validate_input,
+            get_logs,
+            add_data_source,
+            list_data_sources,
+            remove_data_source,
+            validate_data_source,
+            preview_data_source
         ])
         .setup(|app| {
+            logging::start_log_pruning_task(Duration::from_secs(
+                logging::DEFAULT_LOG_RETENTION_SECS,
+            ));
             info!("Application setup complete");
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}